@@ -1,17 +1,25 @@
 use std::{
+    collections::{HashMap, HashSet},
     fs::{self, File},
     io::Write,
     path::{Path, PathBuf},
+    sync::Mutex,
 };
 
 use anyhow::Result;
 use clap::Parser;
 use lazy_static::lazy_static;
 use minijinja::{context, value::Value, Environment, Source, State};
-use svd_parser::svd::{Access, FieldInfo, MaybeArray, PeripheralInfo, RegisterInfo};
+use svd_parser::svd::{
+    Access, FieldInfo, MaybeArray, PeripheralInfo, RegisterCluster, RegisterInfo, Usage,
+};
 
 lazy_static! {
     static ref ENV: Environment<'static> = create_environment();
+
+    // Populated once up front in `main` so the `search` template function can
+    // filter over it while rendering every page.
+    static ref SEARCH_INDEX: Mutex<Vec<Value>> = Mutex::new(Vec::new());
 }
 
 #[derive(Parser, Debug)]
@@ -21,9 +29,21 @@ struct Opts {
     #[clap(short, long)]
     input: PathBuf,
 
-    /// Directory to write generated HTML files
+    /// Directory to write generated files
     #[clap(short, long, default_value = "output")]
     output: PathBuf,
+
+    /// Output format to emit
+    #[clap(short, long, value_enum, default_value = "html")]
+    emit: Emit,
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum Emit {
+    /// A browsable HTML datasheet, one page per peripheral plus an index.
+    Html,
+    /// A `no_std` Rust register-access module.
+    Rust,
 }
 
 fn main() -> Result<()> {
@@ -38,30 +58,57 @@ fn main() -> Result<()> {
     let xml = fs::read_to_string(&opts.input)?;
     let svd = svd_parser::parse(&xml).unwrap();
 
-    // Convert the Vector of `MaybeArray<PeirpheralInfo>` to a Vector of just
-    // `PeripheralInfo`.
-    let peripherals = svd
-        .peripherals
-        .iter()
-        .filter_map(|p| match p {
-            MaybeArray::Single(pi) => Some(pi),
-            MaybeArray::Array(..) => unreachable!(), // Is it, though? ;)
-        })
-        .collect::<Vec<_>>();
+    // Convert the Vector of `MaybeArray<PeripheralInfo>` to a Vector of just
+    // `PeripheralInfo`, expanding any `MaybeArray::Array` (e.g. GPIO banks,
+    // TIMx, USARTx) into one concrete instance per `<dim>` entry.
+    let peripherals = expand_peripherals(&svd.peripherals);
+
+    // Resolve `derivedFrom` references (peripheral, register, and field
+    // level) so every page below renders a complete definition instead of
+    // whatever the derived entry overrides on its own.
+    let peripherals = resolve_derived_from(peripherals)?;
 
-    // Render each peripheral page. List each interupt and register, as well as each
-    // register's fields.
     let chip = svd.name.clone();
-    for peripheral in &peripherals {
-        let filename = format!("{}.html", peripheral.name);
-        let html = render_peripheral(&chip, peripheral)?;
-        write_html(&html, &opts.output.join(filename))?;
-    }
+    let default_size = svd.default_register_properties.size;
+
+    match opts.emit {
+        Emit::Html => {
+            // Known peripheral/register names so descriptions that mention
+            // one can be rewritten into a link to its page/anchor.
+            let links = build_link_map(&peripherals);
+
+            // Flatten every peripheral, register, and field into search
+            // records, make them available to the `search` template
+            // function, and dump them alongside the HTML too.
+            let search_index = build_search_index(&peripherals);
+            *SEARCH_INDEX.lock().unwrap() = search_index.iter().map(search_record_value).collect();
+            write_output(
+                &search_index_json(&search_index),
+                &opts.output.join("search.json"),
+            )?;
 
-    // Render the index page, which lists all peripherals for a device with links to
-    // each peripheral's page.
-    let html = render_index(&chip, &peripherals)?;
-    write_html(&html, &opts.output.join("index.html"))?;
+            // Render each peripheral page. List each interupt and register, as well as
+            // each register's fields.
+            for peripheral in &peripherals {
+                let filename = format!("{}.html", peripheral.name);
+                let html = render_peripheral(&chip, peripheral, default_size, &links)?;
+                write_output(&html, &opts.output.join(filename))?;
+            }
+
+            // Render the index page, which lists all peripherals for a device with
+            // links to each peripheral's page.
+            let html = render_index(&chip, &peripherals)?;
+            write_output(&html, &opts.output.join("index.html"))?;
+        }
+        Emit::Rust => {
+            // Emit a single `no_std` peripheral-access module covering every
+            // peripheral, built from the same register/field model the HTML
+            // output uses.
+            let rust = render_rust(&chip, &peripherals, default_size);
+            let filename = format!("{}.rs", chip.to_lowercase());
+            write_output(&rust, &opts.output.join(filename))?;
+        }
+    }
 
     Ok(())
 }
@@ -84,10 +131,34 @@ fn create_environment() -> Environment<'static> {
     }
     env.add_function("include_file", include_file);
 
+    // Lets a template do prefix/substring filtering over `search.json` at
+    // render time without shipping its own copy of the matching logic.
+    fn search(_state: &State, query: String) -> std::result::Result<Vec<Value>, Error> {
+        let query = query.to_lowercase();
+
+        let matches = SEARCH_INDEX
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|record| {
+                record
+                    .get_attr("name")
+                    .ok()
+                    .and_then(|name| name.as_str().map(|s| s.to_lowercase()))
+                    .map(|name| name.starts_with(&query) || name.contains(&query))
+                    .unwrap_or(false)
+            })
+            .cloned()
+            .collect();
+
+        Ok(matches)
+    }
+    env.add_function("search", search);
+
     env
 }
 
-fn render_index(chip: &str, peripherals: &[&PeripheralInfo]) -> Result<String> {
+fn render_index(chip: &str, peripherals: &[PeripheralInfo]) -> Result<String> {
     // Iterate through all peripherals, and constructor a Vector of Context
     // containing the name and description for each.
     let peripheral_info = peripherals
@@ -113,7 +184,12 @@ fn render_index(chip: &str, peripherals: &[&PeripheralInfo]) -> Result<String> {
     Ok(html)
 }
 
-fn render_peripheral(chip: &str, peripheral: &PeripheralInfo) -> Result<String> {
+fn render_peripheral(
+    chip: &str,
+    peripheral: &PeripheralInfo,
+    default_size: Option<u32>,
+    links: &HashMap<String, String>,
+) -> Result<String> {
     // Build the template context.
     let ctx = context! {
         chip        => chip,
@@ -121,7 +197,7 @@ fn render_peripheral(chip: &str, peripheral: &PeripheralInfo) -> Result<String>
         address     => format!("0x{:08x}", peripheral.base_address),
         description => peripheral.description.clone().unwrap_or_default(),
         interrupts  => interrupts(peripheral),
-        registers   => registers(peripheral),
+        registers   => registers(peripheral, default_size, links),
     };
 
     // Render the template to HTML using the context defined above.
@@ -131,6 +207,460 @@ fn render_peripheral(chip: &str, peripheral: &PeripheralInfo) -> Result<String>
     Ok(html)
 }
 
+/// Emit a `no_std` peripheral-access module for the whole device: one struct
+/// per peripheral holding its base address, with typed register accessors and
+/// per-field getter/setter helpers derived from the same bit spans the HTML
+/// output renders.
+fn render_rust(chip: &str, peripherals: &[PeripheralInfo], default_size: Option<u32>) -> String {
+    let mut out = String::new();
+
+    out.push_str(&format!("//! Peripheral access for {chip}, generated by svd2html.\n"));
+    out.push_str("#![no_std]\n\n");
+    out.push_str("use core::ptr::{read_volatile, write_volatile};\n\n");
+
+    for peripheral in peripherals {
+        out.push_str(&render_rust_peripheral(peripheral, default_size));
+        out.push('\n');
+    }
+
+    out
+}
+
+fn render_rust_peripheral(peripheral: &PeripheralInfo, default_size: Option<u32>) -> String {
+    let mut out = String::new();
+
+    if let Some(description) = &peripheral.description {
+        out.push_str(&format!("/// {description}\n"));
+    }
+    out.push_str(&format!("pub struct {} {{\n", peripheral.name));
+    out.push_str("    base_address: usize,\n");
+    out.push_str("}\n\n");
+
+    out.push_str(&format!("impl {} {{\n", peripheral.name));
+    out.push_str(&format!(
+        "    /// Create a handle for this peripheral, normally at `0x{:08x}`.\n",
+        peripheral.base_address
+    ));
+    out.push_str("    pub const fn new(base_address: usize) -> Self {\n");
+    out.push_str("        Self { base_address }\n");
+    out.push_str("    }\n");
+
+    let top_level = peripheral
+        .registers
+        .as_ref()
+        .map(Vec::as_slice)
+        .unwrap_or(&[]);
+    let mut seen = HashSet::new();
+    render_rust_registers(top_level, peripheral, 0, "", default_size, &mut seen, &mut out);
+
+    out.push_str("}\n");
+
+    out
+}
+
+/// Recursively walk `RegisterCluster` entries the same way `collect_registers`
+/// does for the HTML output, accumulating the offset from the peripheral's
+/// base address through any nested (and possibly arrayed) clusters, so a
+/// register's generated accessor reads/writes the correct address. `prefix`
+/// accumulates the (already `%s`-resolved) cluster path a register is nested
+/// under, so e.g. `ch0` and `ch1` instances of the same cluster generate
+/// distinctly-named accessors instead of colliding.
+fn render_rust_registers(
+    items: &[RegisterCluster],
+    peripheral: &PeripheralInfo,
+    offset_base: u64,
+    prefix: &str,
+    default_size: Option<u32>,
+    seen: &mut HashSet<String>,
+    out: &mut String,
+) {
+    for rc in items {
+        match rc {
+            RegisterCluster::Register(register) => {
+                let ri = match register {
+                    MaybeArray::Single(ri) => ri,
+                    MaybeArray::Array(ri, _) => ri,
+                };
+
+                let width = register_width(ri, peripheral, default_size);
+                render_rust_register(register, width, offset_base, prefix, seen, out);
+            }
+            RegisterCluster::Cluster(register_cluster) => {
+                let (ci, dim, dim_increment) = match register_cluster {
+                    MaybeArray::Single(ci) => (ci, 1u32, 0u64),
+                    MaybeArray::Array(ci, de) => (ci, de.dim, de.dim_increment as u64),
+                };
+
+                for i in 0..dim.max(1) {
+                    let cluster_offset =
+                        offset_base + ci.address_offset as u64 + i as u64 * dim_increment;
+                    let cluster_name = ci
+                        .name
+                        .replace("%s", &i.to_string())
+                        .replace("%c", &i.to_string())
+                        .to_lowercase();
+                    let nested_prefix = if prefix.is_empty() {
+                        cluster_name
+                    } else {
+                        format!("{prefix}_{cluster_name}")
+                    };
+
+                    render_rust_registers(
+                        &ci.children,
+                        peripheral,
+                        cluster_offset,
+                        &nested_prefix,
+                        default_size,
+                        seen,
+                        out,
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Emit a register's `read_`/`write_` accessors and field helpers. A register
+/// array is expanded into one set of accessors per element (mirroring the
+/// `%s`/`dimIndex` expansion `expand_peripherals` does for peripherals)
+/// instead of collapsing to a single accessor at the base offset, and every
+/// generated name is checked against `seen` so a name collision — two cluster
+/// instances or a cluster register and a top-level register sharing a name —
+/// is skipped with a comment rather than emitted twice into the same `impl`.
+fn render_rust_register(
+    register: &MaybeArray<RegisterInfo>,
+    width: u32,
+    offset_base: u64,
+    prefix: &str,
+    seen: &mut HashSet<String>,
+    out: &mut String,
+) {
+    let ri = match register {
+        MaybeArray::Single(ri) => ri,
+        MaybeArray::Array(ri, _) => ri,
+    };
+    let ty = rust_int_type(width);
+
+    let (dim_increment, indices) = match register {
+        MaybeArray::Single(_) => (0u64, vec![String::new()]),
+        MaybeArray::Array(_, de) => (
+            de.dim_increment as u64,
+            de.dim_index
+                .clone()
+                .unwrap_or_else(|| (0..de.dim).map(|i| i.to_string()).collect()),
+        ),
+    };
+
+    for (i, index) in indices.iter().enumerate() {
+        let offset = offset_base + ri.address_offset as u64 + i as u64 * dim_increment;
+        let name = ri.name.replace("%s", index).replace("%c", index);
+        let reg = if prefix.is_empty() {
+            name.to_lowercase()
+        } else {
+            format!("{prefix}_{}", name.to_lowercase())
+        };
+
+        if !seen.insert(reg.clone()) {
+            out.push_str(&format!(
+                "\n    // skipped `{reg}`: name collides with another generated register accessor\n"
+            ));
+            continue;
+        }
+
+        out.push('\n');
+        if let Some(description) = &ri.description {
+            out.push_str(&format!("    /// `{name}` \u{2014} {description}\n"));
+        } else {
+            out.push_str(&format!("    /// `{name}` register.\n"));
+        }
+        out.push_str(&format!("    pub fn read_{reg}(&self) -> {ty} {{\n"));
+        out.push_str(&format!(
+            "        unsafe {{ read_volatile((self.base_address + 0x{offset:x}) as *const {ty}) }}\n"
+        ));
+        out.push_str("    }\n\n");
+
+        out.push_str(&format!("    pub fn write_{reg}(&self, value: {ty}) {{\n"));
+        out.push_str(&format!(
+            "        unsafe {{ write_volatile((self.base_address + 0x{offset:x}) as *mut {ty}, value) }}\n"
+        ));
+        out.push_str("    }\n");
+
+        // Walk the real fields directly rather than `fields_with_spans`: that
+        // helper duplicates the `Some(f)` payload into the reserved-bit gap/pad
+        // entries it synthesizes, which would otherwise emit the same getter,
+        // setter, and enum consts twice per field.
+        for f in register.fields() {
+            let fi = match f {
+                MaybeArray::Single(fi) => fi,
+                MaybeArray::Array(fi, _) => fi,
+            };
+
+            let from = fi.bit_offset() + fi.bit_width() - 1;
+            let to = fi.bit_offset();
+
+            out.push_str(&render_rust_field(&reg, fi, from, to, ty));
+        }
+    }
+}
+
+fn render_rust_field(
+    reg: &str,
+    field: &FieldInfo,
+    from: u32,
+    to: u32,
+    ty: &str,
+) -> String {
+    let mut out = String::new();
+
+    let name = field.name.to_lowercase();
+    let span = from - to + 1;
+    let mask: u64 = if span >= 64 { u64::MAX } else { (1u64 << span) - 1 };
+
+    out.push_str(&format!("\n    /// `{}` field, bits {from}..={to}.\n", field.name));
+    out.push_str(&format!("    pub fn {reg}_{name}(&self) -> {ty} {{\n"));
+    out.push_str(&format!(
+        "        ((self.read_{reg}() >> {to}) & 0x{mask:x}) as {ty}\n"
+    ));
+    out.push_str("    }\n\n");
+
+    out.push_str(&format!("    pub fn set_{reg}_{name}(&self, value: {ty}) {{\n"));
+    out.push_str(&format!("        let mut reg = self.read_{reg}();\n"));
+    out.push_str(&format!("        reg &= !((0x{mask:x} as {ty}) << {to});\n"));
+    out.push_str(&format!(
+        "        reg |= ((value as {ty}) & (0x{mask:x} as {ty})) << {to};\n"
+    ));
+    out.push_str(&format!("        self.write_{reg}(reg);\n"));
+    out.push_str("    }\n");
+
+    for ev in &field.enumerated_values {
+        for value in &ev.values {
+            let Some(v) = value.value else { continue };
+            let const_name = format!("{reg}_{name}_{}", value.name).to_uppercase();
+            out.push_str(&format!("    pub const {const_name}: {ty} = {v};\n"));
+        }
+    }
+
+    out
+}
+
+fn rust_int_type(width: u32) -> &'static str {
+    match width {
+        8 => "u8",
+        16 => "u16",
+        64 => "u64",
+        _ => "u32",
+    }
+}
+
+fn expand_peripherals(peripherals: &[MaybeArray<PeripheralInfo>]) -> Vec<PeripheralInfo> {
+    peripherals
+        .iter()
+        .flat_map(|p| match p {
+            MaybeArray::Single(pi) => vec![pi.clone()],
+            MaybeArray::Array(pi, de) => {
+                let indices = de
+                    .dim_index
+                    .clone()
+                    .unwrap_or_else(|| (0..de.dim).map(|i| i.to_string()).collect());
+
+                indices
+                    .iter()
+                    .enumerate()
+                    .map(|(i, index)| {
+                        let mut instance = pi.clone();
+                        instance.name = pi.name.replace("%s", index).replace("%c", index);
+                        instance.base_address =
+                            pi.base_address + i as u64 * de.dim_increment as u64;
+                        instance
+                    })
+                    .collect::<Vec<_>>()
+            }
+        })
+        .collect()
+}
+
+/// Resolve `derivedFrom` for every peripheral, and then for the registers and
+/// fields within each. Peripherals are resolved in dependency order (a
+/// peripheral is only merged once its base is fully resolved), with a cycle
+/// in the `derivedFrom` chain reported as an error instead of recursing
+/// forever.
+fn resolve_derived_from(peripherals: Vec<PeripheralInfo>) -> Result<Vec<PeripheralInfo>> {
+    let originals = peripherals
+        .iter()
+        .map(|p| (p.name.clone(), p.clone()))
+        .collect::<HashMap<_, _>>();
+
+    let mut resolved = HashMap::new();
+    let mut in_progress = HashSet::new();
+
+    for name in originals.keys() {
+        resolve_peripheral(name, &originals, &mut resolved, &mut in_progress)?;
+    }
+
+    Ok(peripherals
+        .iter()
+        .map(|p| resolved.remove(&p.name).unwrap())
+        .collect())
+}
+
+fn resolve_peripheral(
+    name: &str,
+    originals: &HashMap<String, PeripheralInfo>,
+    resolved: &mut HashMap<String, PeripheralInfo>,
+    in_progress: &mut HashSet<String>,
+) -> Result<PeripheralInfo> {
+    if let Some(peripheral) = resolved.get(name) {
+        return Ok(peripheral.clone());
+    }
+
+    let mut peripheral = originals
+        .get(name)
+        .ok_or_else(|| anyhow::anyhow!("derivedFrom references an unknown peripheral `{name}`"))?
+        .clone();
+
+    if let Some(base_name) = peripheral.derived_from.clone() {
+        if !in_progress.insert(name.to_string()) {
+            anyhow::bail!("cycle detected in peripheral derivedFrom chain at `{name}`");
+        }
+
+        let base = resolve_peripheral(&base_name, originals, resolved, in_progress)?;
+        in_progress.remove(name);
+
+        merge_peripheral(&mut peripheral, &base);
+    }
+
+    resolve_register_derived_from(&mut peripheral)?;
+
+    resolved.insert(name.to_string(), peripheral.clone());
+    Ok(peripheral)
+}
+
+/// Copy whatever the derived peripheral does not already override from its
+/// base: interrupts wholesale, registers keyed by name.
+fn merge_peripheral(peripheral: &mut PeripheralInfo, base: &PeripheralInfo) {
+    if peripheral.interrupt.is_empty() {
+        peripheral.interrupt = base.interrupt.clone();
+    }
+
+    let existing = peripheral
+        .registers
+        .iter()
+        .flatten()
+        .map(register_cluster_name)
+        .collect::<HashSet<_>>();
+
+    if let Some(base_registers) = &base.registers {
+        let inherited = base_registers
+            .iter()
+            .filter(|rc| !existing.contains(&register_cluster_name(rc)))
+            .cloned();
+
+        peripheral
+            .registers
+            .get_or_insert_with(Vec::new)
+            .extend(inherited);
+    }
+}
+
+fn register_cluster_name(rc: &RegisterCluster) -> String {
+    match rc {
+        RegisterCluster::Register(MaybeArray::Single(ri)) => ri.name.clone(),
+        RegisterCluster::Register(MaybeArray::Array(ri, _)) => ri.name.clone(),
+        RegisterCluster::Cluster(MaybeArray::Single(ci)) => ci.name.clone(),
+        RegisterCluster::Cluster(MaybeArray::Array(ci, _)) => ci.name.clone(),
+    }
+}
+
+/// Resolve register- and field-level `derivedFrom` within a single,
+/// already peripheral-resolved, peripheral.
+fn resolve_register_derived_from(peripheral: &mut PeripheralInfo) -> Result<()> {
+    let Some(registers) = &mut peripheral.registers else {
+        return Ok(());
+    };
+
+    let originals = registers
+        .iter()
+        .filter_map(|rc| match rc {
+            RegisterCluster::Register(MaybeArray::Single(ri)) => {
+                Some((ri.name.clone(), ri.clone()))
+            }
+            RegisterCluster::Register(MaybeArray::Array(ri, _)) => {
+                Some((ri.name.clone(), ri.clone()))
+            }
+            RegisterCluster::Cluster(_) => None,
+        })
+        .collect::<HashMap<_, _>>();
+
+    for rc in registers.iter_mut() {
+        let RegisterCluster::Register(register) = rc else {
+            continue;
+        };
+
+        let ri = match register {
+            MaybeArray::Single(ri) => ri,
+            MaybeArray::Array(ri, _) => ri,
+        };
+
+        if let Some(base_name) = ri.derived_from.clone() {
+            let base = originals.get(&base_name).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "register `{}` derivedFrom an unknown register `{base_name}`",
+                    ri.name
+                )
+            })?;
+
+            if ri.fields.is_none() {
+                ri.fields = base.fields.clone();
+            }
+        }
+
+        resolve_field_derived_from(ri)?;
+    }
+
+    Ok(())
+}
+
+fn resolve_field_derived_from(register: &mut RegisterInfo) -> Result<()> {
+    let Some(fields) = &mut register.fields else {
+        return Ok(());
+    };
+
+    let originals = fields
+        .iter()
+        .map(|f| match f {
+            MaybeArray::Single(fi) => (fi.name.clone(), fi.clone()),
+            MaybeArray::Array(fi, _) => (fi.name.clone(), fi.clone()),
+        })
+        .collect::<HashMap<_, _>>();
+
+    for f in fields.iter_mut() {
+        let fi = match f {
+            MaybeArray::Single(fi) => fi,
+            MaybeArray::Array(fi, _) => fi,
+        };
+
+        let Some(base_name) = fi.derived_from.clone() else {
+            continue;
+        };
+
+        let base = originals.get(&base_name).ok_or_else(|| {
+            anyhow::anyhow!(
+                "field `{}` derivedFrom an unknown field `{base_name}`",
+                fi.name
+            )
+        })?;
+
+        if fi.enumerated_values.is_empty() {
+            fi.enumerated_values = base.enumerated_values.clone();
+        }
+        if fi.access.is_none() {
+            fi.access = base.access.clone();
+        }
+    }
+
+    Ok(())
+}
+
 fn interrupts(peripheral: &PeripheralInfo) -> Vec<Value> {
     peripheral
         .interrupt
@@ -145,38 +675,128 @@ fn interrupts(peripheral: &PeripheralInfo) -> Vec<Value> {
         .collect::<Vec<_>>()
 }
 
-fn registers(peripheral: &PeripheralInfo) -> Vec<Value> {
-    peripheral
-        .registers()
-        .map(|register| {
-            let (ri, dim) = match register {
-                MaybeArray::Single(ri) => (ri, 0u32),
-                MaybeArray::Array(ri, de) => (ri, de.dim),
-            };
+fn registers(
+    peripheral: &PeripheralInfo,
+    default_size: Option<u32>,
+    links: &HashMap<String, String>,
+) -> Vec<Value> {
+    let mut out = Vec::new();
 
-            let absolute = peripheral.base_address + ri.address_offset as u64;
+    let top_level = peripheral
+        .registers
+        .as_ref()
+        .map(Vec::as_slice)
+        .unwrap_or(&[]);
 
-            context! {
-                name        => ri.name.replace("%s", &format!("<0..{dim}>")),
-                description => ri.description.clone().unwrap_or_default(),
-                offset      => format!("0x{:04x}", ri.address_offset),
-                absolute    => format!("0x{:08x}", absolute),
-                fields      => fields(register),
+    collect_registers(
+        top_level,
+        peripheral,
+        peripheral.base_address,
+        None,
+        default_size,
+        links,
+        &mut out,
+    );
+
+    out
+}
+
+/// Recursively walk `RegisterCluster` entries, flattening nested clusters
+/// (which can themselves be arrayed) into the same register list `registers`
+/// returns, tagging each with the name of the cluster it came from (if any)
+/// and an absolute address computed from the accumulated cluster base.
+fn collect_registers(
+    items: &[RegisterCluster],
+    peripheral: &PeripheralInfo,
+    base_address: u64,
+    cluster: Option<&str>,
+    default_size: Option<u32>,
+    links: &HashMap<String, String>,
+    out: &mut Vec<Value>,
+) {
+    for rc in items {
+        match rc {
+            RegisterCluster::Register(register) => {
+                let (ri, dim) = match register {
+                    MaybeArray::Single(ri) => (ri, 0u32),
+                    MaybeArray::Array(ri, de) => (ri, de.dim),
+                };
+
+                let absolute = base_address + ri.address_offset as u64;
+                let width = register_width(ri, peripheral, default_size);
+                let description = ri.description.clone().unwrap_or_default();
+
+                out.push(context! {
+                    name        => ri.name.replace("%s", &format!("<0..{dim}>")),
+                    description => Value::from_safe_string(link_cross_references(&description, links)),
+                    offset      => format!("0x{:04x}", ri.address_offset),
+                    absolute    => format!("0x{:08x}", absolute),
+                    cluster     => cluster,
+                    fields      => fields(register, width, links),
+                });
             }
-        })
-        .collect::<Vec<_>>()
+            RegisterCluster::Cluster(register_cluster) => {
+                let (ci, dim, dim_increment) = match register_cluster {
+                    MaybeArray::Single(ci) => (ci, 1u32, 0u64),
+                    MaybeArray::Array(ci, de) => (ci, de.dim, de.dim_increment as u64),
+                };
+
+                for i in 0..dim.max(1) {
+                    let name = ci.name.replace("%s", &i.to_string());
+                    let cluster_base =
+                        base_address + ci.address_offset as u64 + i as u64 * dim_increment;
+
+                    collect_registers(
+                        &ci.children,
+                        peripheral,
+                        cluster_base,
+                        Some(&name),
+                        default_size,
+                        links,
+                        out,
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Resolve a register's width in bits, falling back from the register's own
+/// `<size>` to the peripheral's default register properties, and finally to
+/// the device's, the way `svd-parser` resolves inherited properties.
+fn register_width(
+    register: &RegisterInfo,
+    peripheral: &PeripheralInfo,
+    default_size: Option<u32>,
+) -> u32 {
+    register
+        .properties
+        .size
+        .or(peripheral.default_register_properties.size)
+        .or(default_size)
+        .unwrap_or(32)
 }
 
-fn fields(register: &MaybeArray<RegisterInfo>) -> Vec<Value> {
-    fields_with_spans(register)
+fn fields(
+    register: &MaybeArray<RegisterInfo>,
+    width: u32,
+    links: &HashMap<String, String>,
+) -> Vec<Value> {
+    // Kept around so a field whose `enumeratedValues` block is only a
+    // `derivedFrom` reference (e.g. a write-only enum reusing its read
+    // counterpart) can look the referenced block up by name.
+    let siblings = register.fields().collect::<Vec<_>>();
+
+    fields_with_spans(register, width)
         .iter()
         .map(|(f, from, to)| {
             let (name, desc, access) = field_info(f);
 
             context! {
                 name        => name,
-                description => desc,
+                description => Value::from_safe_string(link_cross_references(&desc, links)),
                 access      => access,
+                enumerated_values => enumerated_values(f, &siblings),
 
                 span => from - to + 1,
                 text => if from == to {
@@ -189,9 +809,66 @@ fn fields(register: &MaybeArray<RegisterInfo>) -> Vec<Value> {
         .collect::<Vec<_>>()
 }
 
+fn enumerated_values(
+    field: &Option<&MaybeArray<FieldInfo>>,
+    siblings: &[&MaybeArray<FieldInfo>],
+) -> Vec<Value> {
+    let Some(f) = field else {
+        return Vec::new();
+    };
+
+    // Kept as one context per `enumeratedValues` block (rather than flattened
+    // into a single list) so a read-write field with separate read and write
+    // blocks renders each under its own `usage` label instead of merging
+    // both sets of values together.
+    f.enumerated_values
+        .iter()
+        .map(|ev| {
+            // A block with `derivedFrom` carries no values of its own; reuse
+            // the sibling field's block it references instead (typically a
+            // write-only enum reusing a read enum's entries).
+            let values = match &ev.derived_from {
+                Some(derived) => siblings
+                    .iter()
+                    .flat_map(|sibling| &sibling.enumerated_values)
+                    .find(|other| other.name.as_deref() == Some(derived.as_str()))
+                    .map(|other| other.values.as_slice())
+                    .unwrap_or(ev.values.as_slice()),
+                None => ev.values.as_slice(),
+            };
+
+            let usage = match ev.usage {
+                Some(Usage::Read) => "read",
+                Some(Usage::Write) => "write",
+                Some(Usage::ReadWrite) => "read-write",
+                None => "read-write",
+            };
+
+            let values = values
+                .iter()
+                .map(|v| {
+                    context! {
+                        value       => v.value.map(|n| format!("0b{:b}", n)).unwrap_or_default(),
+                        name        => v.name.clone(),
+                        description => v.description.clone().unwrap_or_default(),
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            context! {
+                usage  => usage,
+                values => values,
+            }
+        })
+        .collect::<Vec<_>>()
+}
+
 fn fields_with_spans(
     register: &MaybeArray<RegisterInfo>,
+    width: u32,
 ) -> Vec<(Option<&MaybeArray<FieldInfo>>, u32, u32)> {
+    let top = width - 1;
+
     let mut fields = register
         .fields()
         .map(|f| {
@@ -216,11 +893,11 @@ fn fields_with_spans(
 
     if !fields.is_empty() {
         let (f, from, _) = fields[0];
-        if from < 31 {
-            fields.insert(0, (f, 31, from + 1));
+        if from < top {
+            fields.insert(0, (f, top, from + 1));
         }
     } else {
-        fields.push((None, 31, 0));
+        fields.push((None, top, 0));
     }
 
     fields
@@ -254,7 +931,237 @@ fn field_info(field: &Option<&MaybeArray<FieldInfo>>) -> (String, String, String
     (name, desc, access)
 }
 
-fn write_html(source: &str, path: &Path) -> Result<()> {
+/// Map every known peripheral and register name to the page/anchor that
+/// documents it, so descriptions mentioning them can be turned into links.
+fn build_link_map(peripherals: &[PeripheralInfo]) -> HashMap<String, String> {
+    let mut links = HashMap::new();
+
+    for peripheral in peripherals {
+        links
+            .entry(peripheral.name.clone())
+            .or_insert_with(|| format!("{}.html", peripheral.name));
+
+        let top_level = peripheral
+            .registers
+            .as_ref()
+            .map(Vec::as_slice)
+            .unwrap_or(&[]);
+        collect_link_entries(top_level, peripheral, &mut links);
+    }
+
+    links
+}
+
+/// Recursively walk `RegisterCluster` entries the same way `collect_registers`
+/// does, so registers nested inside clusters get a link-map entry too.
+fn collect_link_entries(
+    items: &[RegisterCluster],
+    peripheral: &PeripheralInfo,
+    links: &mut HashMap<String, String>,
+) {
+    for rc in items {
+        match rc {
+            RegisterCluster::Register(register) => {
+                let ri = match register {
+                    MaybeArray::Single(ri) => ri,
+                    MaybeArray::Array(ri, _) => ri,
+                };
+
+                links
+                    .entry(ri.name.clone())
+                    .or_insert_with(|| format!("{}.html#{}", peripheral.name, ri.name));
+            }
+            RegisterCluster::Cluster(register_cluster) => {
+                let ci = match register_cluster {
+                    MaybeArray::Single(ci) => ci,
+                    MaybeArray::Array(ci, _) => ci,
+                };
+
+                collect_link_entries(&ci.children, peripheral, links);
+            }
+        }
+    }
+}
+
+/// Rewrite any word in `text` that names a known peripheral or register into
+/// a link to its page/anchor, turning datasheet-style references ("see
+/// TIMER0") into clickable cross-references.
+fn link_cross_references(text: &str, links: &HashMap<String, String>) -> String {
+    fn flush(word: &mut String, out: &mut String, links: &HashMap<String, String>) {
+        match links.get(word.as_str()) {
+            Some(href) => out.push_str(&format!("<a href=\"{href}\">{word}</a>")),
+            None => out.push_str(word),
+        }
+        word.clear();
+    }
+
+    if links.is_empty() {
+        return text.to_string();
+    }
+
+    let mut out = String::with_capacity(text.len());
+    let mut word = String::new();
+
+    for c in text.chars() {
+        if c.is_ascii_alphanumeric() || c == '_' {
+            word.push(c);
+        } else {
+            flush(&mut word, &mut out, links);
+            out.push(c);
+        }
+    }
+    flush(&mut word, &mut out, links);
+
+    out
+}
+
+/// One flattened, searchable entry for a peripheral, register, or field —
+/// the unit of `search.json` and of the `search` template function.
+struct SearchRecord {
+    kind: &'static str,
+    name: String,
+    peripheral: String,
+    offset: String,
+    description: String,
+    href: String,
+}
+
+fn build_search_index(peripherals: &[PeripheralInfo]) -> Vec<SearchRecord> {
+    let mut records = Vec::new();
+
+    for peripheral in peripherals {
+        records.push(SearchRecord {
+            kind: "peripheral",
+            name: peripheral.name.clone(),
+            peripheral: peripheral.name.clone(),
+            offset: format!("0x{:08x}", peripheral.base_address),
+            description: peripheral.description.clone().unwrap_or_default(),
+            href: format!("{}.html", peripheral.name),
+        });
+
+        let top_level = peripheral
+            .registers
+            .as_ref()
+            .map(Vec::as_slice)
+            .unwrap_or(&[]);
+        collect_search_records(top_level, peripheral, &mut records);
+    }
+
+    records
+}
+
+/// Recursively walk `RegisterCluster` entries the same way `collect_registers`
+/// does, so registers (and their fields) nested inside clusters are
+/// searchable too.
+fn collect_search_records(
+    items: &[RegisterCluster],
+    peripheral: &PeripheralInfo,
+    records: &mut Vec<SearchRecord>,
+) {
+    for rc in items {
+        match rc {
+            RegisterCluster::Register(register) => {
+                let ri = match register {
+                    MaybeArray::Single(ri) => ri,
+                    MaybeArray::Array(ri, _) => ri,
+                };
+
+                records.push(SearchRecord {
+                    kind: "register",
+                    name: ri.name.clone(),
+                    peripheral: peripheral.name.clone(),
+                    offset: format!("0x{:04x}", ri.address_offset),
+                    description: ri.description.clone().unwrap_or_default(),
+                    href: format!("{}.html#{}", peripheral.name, ri.name),
+                });
+
+                // Iterate the real fields directly: `fields_with_spans` also
+                // synthesizes reserved-bit gap/pad entries that carry the
+                // adjacent field's `Some(f)`, which would otherwise duplicate
+                // that field's search record.
+                for f in register.fields() {
+                    let fi = match f {
+                        MaybeArray::Single(fi) => fi,
+                        MaybeArray::Array(fi, _) => fi,
+                    };
+
+                    records.push(SearchRecord {
+                        kind: "field",
+                        name: fi.name.clone(),
+                        peripheral: peripheral.name.clone(),
+                        offset: format!("0x{:04x}", ri.address_offset),
+                        description: fi.description.clone().unwrap_or_default(),
+                        href: format!("{}.html#{}-{}", peripheral.name, ri.name, fi.name),
+                    });
+                }
+            }
+            RegisterCluster::Cluster(register_cluster) => {
+                let ci = match register_cluster {
+                    MaybeArray::Single(ci) => ci,
+                    MaybeArray::Array(ci, _) => ci,
+                };
+
+                collect_search_records(&ci.children, peripheral, records);
+            }
+        }
+    }
+}
+
+fn search_record_value(record: &SearchRecord) -> Value {
+    context! {
+        kind        => record.kind,
+        name        => record.name.clone(),
+        peripheral  => record.peripheral.clone(),
+        offset      => record.offset.clone(),
+        description => record.description.clone(),
+        href        => record.href.clone(),
+    }
+}
+
+fn search_index_json(records: &[SearchRecord]) -> String {
+    let mut out = String::from("[\n");
+
+    for (i, record) in records.iter().enumerate() {
+        if i > 0 {
+            out.push_str(",\n");
+        }
+
+        out.push_str(&format!(
+            "  {{\"kind\": {}, \"name\": {}, \"peripheral\": {}, \"offset\": {}, \"description\": {}, \"href\": {}}}",
+            json_string(record.kind),
+            json_string(&record.name),
+            json_string(&record.peripheral),
+            json_string(&record.offset),
+            json_string(&record.description),
+            json_string(&record.href),
+        ));
+    }
+
+    out.push_str("\n]\n");
+    out
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+
+    out.push('"');
+    out
+}
+
+fn write_output(source: &str, path: &Path) -> Result<()> {
     eprintln!("Writing: {}", path.display());
 
     let mut file = File::create(path)?;